@@ -0,0 +1,179 @@
+use crate::types::{CryptoHash, PlayerId};
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug)]
+pub enum DeckError {
+    NotEnoughPlayers,
+    AlreadyStarted,
+    WrongPhase,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Eq, PartialEq, Clone, Debug)]
+pub enum DeckStatus {
+    // Entering is still open.
+    Initiating,
+    // Waiting for each player in turn to submit their partial shuffle.
+    Shuffling,
+    // Waiting for each player in turn to submit their reveal share.
+    Revealing,
+    // Shuffled and nothing is currently pending; `Poker` drives the hand.
+    Running,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Clone)]
+struct Commitment {
+    player: PlayerId,
+    // ElGamal public key h_i = g^x_i, committed by the player when they enter
+    // the room. Reveal shares they later submit are checked against this in
+    // `RevealProof::verify`, so it must be the player's real key, not a
+    // placeholder: a constant here would let anyone forge a passing proof.
+    public_key: CryptoHash,
+}
+
+// Deck size and the mental-poker shuffle/reveal cryptography are out of
+// scope for this change; this is a minimal state machine standing in for
+// them so `Game`'s deck-driven transitions have something real to call.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Clone)]
+pub struct Deck {
+    size: u32,
+    status: DeckStatus,
+    commitments: Vec<Commitment>,
+    next_player_id: u8,
+    shuffle_turn: usize,
+    reveal_turn: usize,
+    ciphertexts: Vec<CryptoHash>,
+}
+
+impl Deck {
+    pub fn new(size: u32) -> Self {
+        Self {
+            size,
+            status: DeckStatus::Initiating,
+            commitments: Vec::new(),
+            next_player_id: 0,
+            shuffle_turn: 0,
+            reveal_turn: 0,
+            ciphertexts: Vec::new(),
+        }
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn enter(&mut self, public_key: CryptoHash) -> Result<PlayerId, DeckError> {
+        if self.status != DeckStatus::Initiating {
+            return Err(DeckError::AlreadyStarted);
+        }
+
+        let player = PlayerId(self.next_player_id);
+        self.next_player_id += 1;
+        self.commitments.push(Commitment { player, public_key });
+        Ok(player)
+    }
+
+    pub fn start(&mut self) -> Result<(), DeckError> {
+        if self.status != DeckStatus::Initiating {
+            return Err(DeckError::AlreadyStarted);
+        }
+        if self.commitments.len() < 2 {
+            return Err(DeckError::NotEnoughPlayers);
+        }
+
+        self.status = DeckStatus::Shuffling;
+        self.shuffle_turn = 0;
+        Ok(())
+    }
+
+    pub fn get_status(&self) -> DeckStatus {
+        self.status.clone()
+    }
+
+    pub fn close(&mut self) {
+        self.status = DeckStatus::Initiating;
+    }
+
+    /// Returns to `Initiating` so the same commitments can shuffle into a
+    /// fresh deck for the next hand, whether this hand ended normally
+    /// (showdown) or was aborted (a stalled shuffle/reveal).
+    pub fn reset(&mut self) {
+        self.status = DeckStatus::Initiating;
+        self.shuffle_turn = 0;
+        self.reveal_turn = 0;
+        self.ciphertexts.clear();
+    }
+
+    pub fn abort(&mut self) {
+        self.reset();
+    }
+
+    pub fn get_partial_shuffle(&self) -> Result<Vec<CryptoHash>, DeckError> {
+        if self.status != DeckStatus::Shuffling {
+            return Err(DeckError::WrongPhase);
+        }
+        Ok(self.ciphertexts.clone())
+    }
+
+    pub fn submit_shuffled(&mut self, new_cards: Vec<CryptoHash>) -> Result<(), DeckError> {
+        if self.status != DeckStatus::Shuffling {
+            return Err(DeckError::WrongPhase);
+        }
+
+        self.ciphertexts = new_cards;
+        self.shuffle_turn += 1;
+        if self.shuffle_turn >= self.commitments.len() {
+            // The shuffled deck still needs its cards decrypted (with a
+            // fault proof each) before play can start on it.
+            self.status = DeckStatus::Revealing;
+            self.reveal_turn = 0;
+        }
+        Ok(())
+    }
+
+    pub fn finish_reveal(&mut self) -> Result<(), DeckError> {
+        if self.status != DeckStatus::Revealing {
+            return Err(DeckError::WrongPhase);
+        }
+
+        self.status = DeckStatus::Running;
+        self.reveal_turn = 0;
+        Ok(())
+    }
+
+    pub fn submit_reveal_part(&mut self, _card: CryptoHash) -> Result<(), DeckError> {
+        if self.status != DeckStatus::Revealing {
+            return Err(DeckError::WrongPhase);
+        }
+
+        self.reveal_turn += 1;
+        if self.reveal_turn >= self.commitments.len() {
+            self.status = DeckStatus::Running;
+        }
+        Ok(())
+    }
+
+    /// Player expected to submit the next shuffle/reveal share, if any.
+    pub fn next_submitter(&self) -> Option<PlayerId> {
+        match self.status {
+            DeckStatus::Shuffling => self.commitments.get(self.shuffle_turn).map(|c| c.player),
+            DeckStatus::Revealing => self.commitments.get(self.reveal_turn).map(|c| c.player),
+            _ => None,
+        }
+    }
+
+    pub fn committed_key(&self, player: PlayerId) -> CryptoHash {
+        self.commitments
+            .iter()
+            .find(|commitment| commitment.player == player)
+            .map(|commitment| commitment.public_key)
+            .unwrap_or([0u8; 32])
+    }
+
+    pub fn reveal_ciphertext(&self) -> CryptoHash {
+        self.ciphertexts
+            .get(self.reveal_turn)
+            .copied()
+            .unwrap_or([0u8; 32])
+    }
+}
@@ -0,0 +1,4 @@
+pub mod deck;
+pub mod game;
+pub mod poker;
+pub mod types;
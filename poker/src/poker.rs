@@ -0,0 +1,466 @@
+use crate::types::{Balance, PlayerId};
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Debug)]
+pub enum PokerError {
+    NoSuchPlayer,
+    NotEnoughPlayers,
+    NothingToCall,
+    BetBelowMinimum,
+    RaiseBelowMinimum,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Eq, PartialEq, Clone, Debug)]
+pub enum PokerStatus {
+    PreFlop,
+    Flop,
+    Turn,
+    River,
+    RoundFinished,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Eq, PartialEq, Clone, Copy)]
+enum Street {
+    PreFlop,
+    Flop,
+    Turn,
+    River,
+}
+
+impl Street {
+    fn status(self) -> PokerStatus {
+        match self {
+            Street::PreFlop => PokerStatus::PreFlop,
+            Street::Flop => PokerStatus::Flop,
+            Street::Turn => PokerStatus::Turn,
+            Street::River => PokerStatus::River,
+        }
+    }
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Clone)]
+struct Seat {
+    player: PlayerId,
+    stack: Balance,
+    // Committed to the pot on the current street.
+    committed: Balance,
+    // Committed to the pot over the whole hand (for side-pot partitioning).
+    total_contributed: Balance,
+    folded: bool,
+    all_in: bool,
+    // Has this seat acted since the current street's bet was last raised?
+    acted: bool,
+}
+
+/// Betting engine for a single table. Tracks stacks, the current street's
+/// `current_bet`/`min_raise` invariant, and partitions the pot into side
+/// pots at each ascending all-in threshold when a hand reaches showdown.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Clone)]
+pub struct Poker {
+    seats: Vec<Seat>,
+    street: Street,
+    current_bet: Balance,
+    min_raise: Balance,
+    big_blind: Balance,
+    acting: usize,
+    // Seat index of the dealer button, advanced by one seat each hand so
+    // blinds rotate around the table instead of always falling on seats 0/1.
+    button: usize,
+}
+
+impl Default for Poker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Poker {
+    pub fn new() -> Self {
+        Self {
+            seats: Vec::new(),
+            street: Street::PreFlop,
+            current_bet: 0,
+            min_raise: 0,
+            big_blind: 0,
+            acting: 0,
+            button: 0,
+        }
+    }
+
+    pub fn num_players(&self) -> usize {
+        self.seats.len()
+    }
+
+    pub fn new_player(&mut self, player: PlayerId, deposit: Balance) {
+        self.seats.push(Seat {
+            player,
+            stack: deposit,
+            committed: 0,
+            total_contributed: 0,
+            folded: false,
+            all_in: deposit == 0,
+            acted: false,
+        });
+    }
+
+    /// Resyncs a seat's live stack to its current off-table balance.
+    ///
+    /// `showdown`/`refund` return winnings to the caller rather than crediting
+    /// them back into the seat directly (the caller owns the real ledger, e.g.
+    /// `Game::escrow`), so the caller must sync stacks back in before posting
+    /// the next hand's blinds or a winner's stack would still read as whatever
+    /// was left after they committed chips to the pot they just won.
+    pub fn sync_stack(&mut self, player: PlayerId, stack: Balance) -> Result<(), PokerError> {
+        let idx = self.index_of(player)?;
+        self.seats[idx].stack = stack;
+        self.seats[idx].all_in = stack == 0;
+        Ok(())
+    }
+
+    /// Posts the small/big blind from the seats after the dealer button and
+    /// opens the pre-flop betting round, then advances the button one seat
+    /// for next time. Returns what each blind actually committed (capped at
+    /// their stack, for an under-stacked all-in blind).
+    pub fn post_blinds(
+        &mut self,
+        small: Balance,
+        big: Balance,
+    ) -> Result<Vec<(PlayerId, Balance)>, PokerError> {
+        if self.seats.len() < 2 {
+            return Err(PokerError::NotEnoughPlayers);
+        }
+
+        self.reset_for_new_hand();
+        self.big_blind = big;
+
+        let n = self.seats.len();
+        let sb_idx = (self.button + 1) % n;
+        let bb_idx = (self.button + 2) % n;
+        self.button = (self.button + 1) % n;
+
+        let sb_amount = self.commit(sb_idx, small);
+        let bb_amount = self.commit(bb_idx, big);
+
+        self.current_bet = bb_amount;
+        self.min_raise = big;
+        self.acting = self.next_active(bb_idx);
+
+        Ok(vec![
+            (self.seats[sb_idx].player, sb_amount),
+            (self.seats[bb_idx].player, bb_amount),
+        ])
+    }
+
+    /// Advances the hand: reports the street still needing action, rolls
+    /// forward to the next street once betting on this one is settled
+    /// (skipping betting once an all-in runout leaves nobody left to act),
+    /// and reports `RoundFinished` once the river settles or only one
+    /// contested player remains.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> PokerStatus {
+        loop {
+            if self.players_in_hand() <= 1 {
+                return PokerStatus::RoundFinished;
+            }
+
+            if self.needs_action() {
+                return self.street.status();
+            }
+
+            match self.advance_street() {
+                Some(status) => return status,
+                None => continue,
+            }
+        }
+    }
+
+    pub fn current_actor(&self) -> Option<PlayerId> {
+        if self.players_in_hand() <= 1 || !self.needs_action() {
+            return None;
+        }
+        self.seats.get(self.acting).map(|seat| seat.player)
+    }
+
+    /// Whether the current street still has a live decision pending.
+    ///
+    /// A lone all-in player can't be acted against, so once at most one seat
+    /// can still act *and* nobody owes a response to a bet (`current_bet` is
+    /// still 0 for this street), the street is trivially over. But a bet or
+    /// raise that itself puts the bettor all-in must still be answered by
+    /// whoever else is live, even though that leaves only one seat able to
+    /// act - the `current_bet > 0` half of this keeps the street alive until
+    /// `street_complete` sees them respond.
+    fn needs_action(&self) -> bool {
+        (self.players_who_can_act() > 1 || self.current_bet > 0) && !self.street_complete()
+    }
+
+    pub fn check(&mut self) -> Result<Balance, PokerError> {
+        let idx = self.acting;
+        if self.seats[idx].committed != self.current_bet {
+            return Err(PokerError::NothingToCall);
+        }
+
+        self.mark_acted(idx);
+        self.advance_actor();
+        Ok(0)
+    }
+
+    pub fn call(&mut self) -> Result<Balance, PokerError> {
+        let idx = self.acting;
+        let owed = self.current_bet.saturating_sub(self.seats[idx].committed);
+        let amount = self.commit(idx, owed);
+
+        self.mark_acted(idx);
+        self.advance_actor();
+        Ok(amount)
+    }
+
+    pub fn bet(&mut self, amount: Balance) -> Result<Balance, PokerError> {
+        let idx = self.acting;
+        if self.current_bet != 0 {
+            return Err(PokerError::NothingToCall);
+        }
+        if amount < self.min_raise.max(self.big_blind) || amount > self.seats[idx].stack {
+            return Err(PokerError::BetBelowMinimum);
+        }
+
+        let committed = self.commit(idx, amount);
+        self.current_bet = committed;
+        self.min_raise = committed;
+        self.reset_acted_except(idx);
+        self.mark_acted(idx);
+        self.advance_actor();
+        Ok(committed)
+    }
+
+    pub fn raise(&mut self, amount: Balance) -> Result<Balance, PokerError> {
+        let idx = self.acting;
+        if self.current_bet == 0 {
+            return Err(PokerError::NothingToCall);
+        }
+        if amount < self.min_raise {
+            return Err(PokerError::RaiseBelowMinimum);
+        }
+
+        let target = self.current_bet + amount;
+        let owed = target.saturating_sub(self.seats[idx].committed);
+        if owed > self.seats[idx].stack {
+            return Err(PokerError::RaiseBelowMinimum);
+        }
+
+        // `taken` is what actually left the stack this action (capped by
+        // `commit`, in case of an under-stacked all-in raise); the caller
+        // uses it to debit the same amount from escrow, so this must stay
+        // the incremental amount and not the seat's cumulative street total.
+        let taken = self.commit(idx, owed);
+        let committed_total = self.seats[idx].committed;
+        self.min_raise = committed_total - self.current_bet;
+        self.current_bet = committed_total;
+        self.reset_acted_except(idx);
+        self.mark_acted(idx);
+        self.advance_actor();
+        Ok(taken)
+    }
+
+    pub fn fold(&mut self) -> Result<Balance, PokerError> {
+        let idx = self.acting;
+        self.seats[idx].folded = true;
+        self.mark_acted(idx);
+        self.advance_actor();
+        Ok(0)
+    }
+
+    /// Folds `player` regardless of whose turn it is; used to resolve a
+    /// stalled action rather than as a normal betting move.
+    pub fn fold_player(&mut self, player: PlayerId) -> Result<(), PokerError> {
+        let idx = self.index_of(player)?;
+        self.seats[idx].folded = true;
+        self.mark_acted(idx);
+        if self.acting == idx {
+            self.advance_actor();
+        }
+        Ok(())
+    }
+
+    /// Partitions the pot into side pots at each ascending all-in threshold
+    /// and pays each one out, then resets for the next hand.
+    pub fn showdown(&mut self) -> Vec<(PlayerId, Balance)> {
+        let payouts = self.settle();
+        self.reset_for_new_hand();
+        payouts
+    }
+
+    /// Returns every seat's contribution this hand so the caller can credit
+    /// it back, used when a deck stall deadlocks the round.
+    pub fn refund(&mut self) -> Vec<(PlayerId, Balance)> {
+        let payouts = self
+            .seats
+            .iter()
+            .map(|seat| (seat.player, seat.total_contributed))
+            .collect();
+        self.reset_for_new_hand();
+        payouts
+    }
+
+    fn index_of(&self, player: PlayerId) -> Result<usize, PokerError> {
+        self.seats
+            .iter()
+            .position(|seat| seat.player == player)
+            .ok_or(PokerError::NoSuchPlayer)
+    }
+
+    fn players_in_hand(&self) -> usize {
+        self.seats.iter().filter(|seat| !seat.folded).count()
+    }
+
+    fn players_who_can_act(&self) -> usize {
+        self.seats
+            .iter()
+            .filter(|seat| !seat.folded && !seat.all_in)
+            .count()
+    }
+
+    fn street_complete(&self) -> bool {
+        self.seats
+            .iter()
+            .all(|seat| seat.folded || seat.all_in || (seat.acted && seat.committed == self.current_bet))
+    }
+
+    fn advance_street(&mut self) -> Option<PokerStatus> {
+        for seat in &mut self.seats {
+            seat.committed = 0;
+            seat.acted = false;
+        }
+        self.current_bet = 0;
+        self.min_raise = self.big_blind;
+
+        self.street = match self.street {
+            Street::PreFlop => Street::Flop,
+            Street::Flop => Street::Turn,
+            Street::Turn => Street::River,
+            Street::River => return Some(PokerStatus::RoundFinished),
+        };
+
+        if self.players_who_can_act() > 1 {
+            self.acting = self.first_active();
+        }
+
+        None
+    }
+
+    fn first_active(&self) -> usize {
+        self.seats
+            .iter()
+            .position(|seat| !seat.folded && !seat.all_in)
+            .unwrap_or(0)
+    }
+
+    fn next_active(&self, from: usize) -> usize {
+        let n = self.seats.len();
+        let mut i = (from + 1) % n;
+        for _ in 0..n {
+            if !self.seats[i].folded && !self.seats[i].all_in {
+                return i;
+            }
+            i = (i + 1) % n;
+        }
+        from
+    }
+
+    fn advance_actor(&mut self) {
+        self.acting = self.next_active(self.acting);
+    }
+
+    fn mark_acted(&mut self, idx: usize) {
+        self.seats[idx].acted = true;
+    }
+
+    fn reset_acted_except(&mut self, idx: usize) {
+        for (i, seat) in self.seats.iter_mut().enumerate() {
+            if i != idx {
+                seat.acted = false;
+            }
+        }
+    }
+
+    fn commit(&mut self, idx: usize, amount: Balance) -> Balance {
+        let seat = &mut self.seats[idx];
+        let amount = amount.min(seat.stack);
+        seat.stack -= amount;
+        seat.committed += amount;
+        seat.total_contributed += amount;
+        if seat.stack == 0 {
+            seat.all_in = true;
+        }
+        amount
+    }
+
+    fn reset_for_new_hand(&mut self) {
+        for seat in &mut self.seats {
+            seat.committed = 0;
+            seat.total_contributed = 0;
+            seat.folded = false;
+            seat.all_in = seat.stack == 0;
+            seat.acted = false;
+        }
+        self.street = Street::PreFlop;
+        self.current_bet = 0;
+        self.min_raise = 0;
+        self.acting = 0;
+    }
+
+    /// Splits the pot into side pots at each ascending all-in threshold and
+    /// awards every pot to its eligible contenders. Hand ranking against the
+    /// revealed community/hole cards is outside this crate's current scope
+    /// (no card-evaluation module exists yet); a pot with more than one
+    /// eligible contender is split evenly between them until that lands.
+    fn settle(&self) -> Vec<(PlayerId, Balance)> {
+        let mut payouts: HashMap<PlayerId, Balance> = HashMap::new();
+
+        let mut thresholds: Vec<Balance> = self
+            .seats
+            .iter()
+            .filter(|seat| !seat.folded)
+            .map(|seat| seat.total_contributed)
+            .collect();
+        thresholds.sort_unstable();
+        thresholds.dedup();
+
+        let mut floor = 0;
+        for &threshold in &thresholds {
+            let layer = threshold - floor;
+            if layer == 0 {
+                continue;
+            }
+
+            let contributors: Vec<usize> = (0..self.seats.len())
+                .filter(|&i| self.seats[i].total_contributed > floor)
+                .collect();
+            let pot: Balance = contributors
+                .iter()
+                .map(|&i| layer.min(self.seats[i].total_contributed - floor))
+                .sum();
+
+            let eligible: Vec<usize> = contributors
+                .iter()
+                .copied()
+                .filter(|&i| !self.seats[i].folded)
+                .collect();
+
+            if !eligible.is_empty() {
+                let share = pot / eligible.len() as Balance;
+                let remainder = pot % eligible.len() as Balance;
+                for (n, &i) in eligible.iter().enumerate() {
+                    let amount = share + if n == 0 { remainder } else { 0 };
+                    *payouts.entry(self.seats[i].player).or_insert(0) += amount;
+                }
+            }
+
+            floor = threshold;
+        }
+
+        payouts.into_iter().collect()
+    }
+}
@@ -0,0 +1,15 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+
+pub type BlockHeight = u64;
+pub type CryptoHash = [u8; 32];
+pub type RoomId = u64;
+
+/// A NEAR token amount. Kept abstract so the escrow/betting bookkeeping can
+/// be exercised in unit tests without running under the NEAR runtime.
+pub type Balance = u128;
+
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug,
+)]
+pub struct PlayerId(pub u8);